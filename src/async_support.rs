@@ -0,0 +1,233 @@
+//! Async front-end for the channel, enabled by the `async` feature.
+//!
+//! [`AsyncReceiver`] implements `futures_core::Stream` so priority tasks can be consumed with
+//! `while let Some(item) = rx.next().await` (via `futures::StreamExt`), and [`AsyncSender`]
+//! exposes an async `send` that, on a bounded channel, awaits room instead of blocking a thread.
+//! Both are built from an existing [`Sender`]/[`Receiver`] with `into_async`.
+
+use crate::{Inner, SendError};
+use futures_core::Stream;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+/// An async counterpart to [`Receiver`](crate::Receiver), built via
+/// [`Receiver::into_async`](crate::Receiver::into_async).
+pub struct AsyncReceiver<T> {
+    pub(crate) inner: Arc<Inner<T>>,
+}
+
+impl<T: std::cmp::Ord> Stream for AsyncReceiver<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let mut state = self.inner.state.lock().expect("Poison error");
+        if let Some(item) = state.queue.pop() {
+            Inner::wake_senders(&mut state);
+            drop(state);
+            // A bounded blocking `Sender` may be parked in `Condvar::wait` on `not_full`; let
+            // it recheck, same as `Receiver::recv_greatest` and friends.
+            self.inner.not_full.notify_one();
+            return Poll::Ready(Some(item));
+        }
+        if state.senders == 0 {
+            return Poll::Ready(None);
+        }
+        // Avoid piling up duplicate wakers for a task that gets polled again (e.g. by
+        // `select!`/`join!` waking on an unrelated branch) while still `Pending` here; they're
+        // only drained on an actual wake, so duplicates would otherwise accumulate forever.
+        if !state.recv_wakers.iter().any(|w| w.will_wake(cx.waker())) {
+            state.recv_wakers.push(cx.waker().clone());
+        }
+        Poll::Pending
+    }
+}
+
+impl<T> Drop for AsyncReceiver<T> {
+    fn drop(&mut self) {
+        let mut state = self.inner.state.lock().expect("Poison error");
+        state.receivers -= 1;
+        if state.receivers == 0 {
+            // Wake every sender blocked on a full bounded queue so they can observe the
+            // disconnect instead of waiting for room that will never open up.
+            self.inner.not_full.notify_all();
+            Inner::wake_senders(&mut state);
+        }
+    }
+}
+
+/// An async counterpart to [`Sender`](crate::Sender), built via
+/// [`Sender::into_async`](crate::Sender::into_async).
+pub struct AsyncSender<T> {
+    pub(crate) inner: Arc<Inner<T>>,
+}
+
+impl<T> Drop for AsyncSender<T> {
+    fn drop(&mut self) {
+        let mut state = self.inner.state.lock().expect("Poison error");
+        state.senders -= 1;
+        if state.senders == 0 {
+            // Wake every blocked receiver, blocking or async, so they can observe the disconnect.
+            self.inner.not_empty.notify_all();
+            Inner::wake_receivers(&mut state);
+        }
+    }
+}
+
+impl<T: std::cmp::Ord> AsyncSender<T> {
+    /// Returns a future that pushes `item` onto the channel. On an unbounded channel this
+    /// resolves immediately; on a channel built with `pmpmc_bounded`, it awaits room in the
+    /// queue instead of blocking a thread.
+    pub fn send(&self, item: T) -> SendFuture<'_, T> {
+        SendFuture {
+            sender: self,
+            item: Some(item),
+        }
+    }
+}
+
+/// Future returned by [`AsyncSender::send`]. Holds no self-references, so it's `Unpin` even
+/// though `T` might not be.
+pub struct SendFuture<'a, T> {
+    sender: &'a AsyncSender<T>,
+    item: Option<T>,
+}
+
+impl<'a, T> Unpin for SendFuture<'a, T> {}
+
+impl<'a, T: std::cmp::Ord> Future for SendFuture<'a, T> {
+    type Output = Result<(), SendError<T>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), SendError<T>>> {
+        let this = self.get_mut();
+        let mut state = this.sender.inner.state.lock().expect("Poison error");
+        if let Some(capacity) = this.sender.inner.capacity {
+            if state.queue.len() >= capacity {
+                if state.receivers == 0 {
+                    let item = this.item.take().expect("SendFuture polled after completion");
+                    return Poll::Ready(Err(SendError(item)));
+                }
+                // Same dedup as `AsyncReceiver::poll_next`.
+                if !state.send_wakers.iter().any(|w| w.will_wake(cx.waker())) {
+                    state.send_wakers.push(cx.waker().clone());
+                }
+                return Poll::Pending;
+            }
+        }
+        let item = this.item.take().expect("SendFuture polled after completion");
+        state.queue.push(item);
+        Inner::wake_receivers(&mut state);
+        drop(state);
+        this.sender.inner.not_empty.notify_one();
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{pmpmc, pmpmc_bounded};
+    use futures::executor::block_on;
+    use futures::StreamExt;
+
+    #[test]
+    fn async_receiver_yields_in_priority_order_then_ends() {
+        let (tx, rx) = pmpmc();
+        tx.send(1).unwrap();
+        tx.send(3).unwrap();
+        tx.send(2).unwrap();
+        drop(tx);
+
+        let rx = rx.into_async();
+        let collected: Vec<i32> = block_on(rx.collect());
+        assert_eq!(collected, vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn async_receiver_wakes_once_an_item_is_sent() {
+        let (tx, rx) = pmpmc();
+        let rx = rx.into_async();
+
+        let handle = std::thread::spawn(move || block_on(rx.collect::<Vec<i32>>()));
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        tx.send(3).unwrap();
+        drop(tx);
+
+        assert_eq!(handle.join().unwrap(), vec![3]);
+    }
+
+    #[test]
+    fn async_sender_send_future_waits_for_capacity() {
+        let (tx, rx) = pmpmc_bounded(1);
+        let tx = tx.into_async();
+        block_on(tx.send(1)).unwrap();
+
+        let handle = std::thread::spawn(move || block_on(tx.send(2)));
+        std::thread::sleep(std::time::Duration::from_millis(50));
+
+        assert_eq!(rx.recv_greatest(), Some(1));
+        handle.join().unwrap().unwrap();
+        assert_eq!(rx.recv_greatest(), Some(2));
+    }
+
+    #[test]
+    fn dropping_the_async_sender_disconnects_the_channel() {
+        let (tx, rx) = pmpmc::<i32>();
+        let tx = tx.into_async();
+        let rx = rx.into_async();
+
+        let handle = std::thread::spawn(move || block_on(rx.collect::<Vec<i32>>()));
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        drop(tx);
+
+        assert_eq!(handle.join().unwrap(), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn async_receiver_wakes_a_blocking_sender_parked_on_a_full_queue() {
+        let (tx, rx) = pmpmc_bounded(1);
+        tx.send(1).unwrap();
+
+        let handle = std::thread::spawn(move || tx.send(2));
+        std::thread::sleep(std::time::Duration::from_millis(50));
+
+        let rx = rx.into_async();
+        let drained: Vec<i32> = block_on(rx.take(1).collect());
+        assert_eq!(drained, vec![1]);
+
+        handle.join().unwrap().unwrap();
+    }
+
+    #[test]
+    fn repeated_pending_polls_do_not_duplicate_the_registered_waker() {
+        use futures::Stream;
+        use std::pin::Pin;
+        use std::task::{Context, Poll, Waker};
+
+        let (_tx, rx) = pmpmc::<i32>();
+        let mut rx = rx.into_async();
+        let waker = Waker::noop().clone();
+        let mut cx = Context::from_waker(&waker);
+
+        for _ in 0..3 {
+            assert_eq!(Pin::new(&mut rx).poll_next(&mut cx), Poll::Pending);
+        }
+        assert_eq!(rx.inner.state.lock().unwrap().recv_wakers.len(), 1);
+    }
+
+    #[test]
+    fn send_future_errors_instead_of_hanging_once_the_last_async_receiver_drops() {
+        use crate::SendError;
+
+        let (tx, rx) = pmpmc_bounded(1);
+        let tx = tx.into_async();
+        let rx = rx.into_async();
+        block_on(tx.send(1)).unwrap();
+
+        let handle = std::thread::spawn(move || block_on(tx.send(2)));
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        drop(rx);
+
+        assert_eq!(handle.join().unwrap(), Err(SendError(2)));
+    }
+}
@@ -13,9 +13,9 @@
 //! let rx_new = rx.clone();
 //!
 //! let _ = std::thread::spawn(move || {
-//!     tx_new.send(2);
-//!     tx_new.send(1);
-//!     tx_new.send(3);
+//!     tx_new.send(2).unwrap();
+//!     tx_new.send(1).unwrap();
+//!     tx_new.send(3).unwrap();
 //! })
 //! .join();
 //!
@@ -28,16 +28,135 @@
 //!
 //! I am using it for automatically scheduling tasks (workers now pull tasks off the channel in order of priority) but you can use it
 //! any time you want to push to a list from one or more threads and sort the list before receiving on another thread.
+//!
+//! Enable the `async` feature to get [`async_support::AsyncReceiver`] and
+//! [`async_support::AsyncSender`], which let the channel be driven from an async executor
+//! (Tokio, async-std, ...) instead of a blocking thread.
 
 // Imports
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Condvar, Mutex};
 use std::collections::BinaryHeap;
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "async")]
+pub mod async_support;
+
+// Everything that needs to change together lives behind the same lock, so a sender
+// disconnecting can never be observed out of sync with the heap's contents.
+struct State<T> {
+    queue: BinaryHeap<T>,
+    senders: usize,
+    receivers: usize,
+    // Wakers registered by async tasks that found the channel empty (receivers) or full
+    // (bounded senders) and need to be notified once that changes.
+    #[cfg(feature = "async")]
+    recv_wakers: Vec<std::task::Waker>,
+    #[cfg(feature = "async")]
+    send_wakers: Vec<std::task::Waker>,
+}
 
 // By convention, we call this struct Inner, but it's just a place to put the Mutex to the Heap
 struct Inner<T> {
-    queue: Mutex<BinaryHeap<T>>,
+    state: Mutex<State<T>>,
+    not_empty: Condvar,
+    // `not_full` and `capacity` only matter for channels built by `pmpmc_bounded`; an unbounded
+    // `pmpmc()` channel leaves `capacity` at `None` and never waits on `not_full`.
+    not_full: Condvar,
+    capacity: Option<usize>,
+}
+
+#[cfg(feature = "async")]
+impl<T> Inner<T> {
+    // Wakes every task parked in `AsyncReceiver::poll_next`, e.g. because an item just
+    // arrived or because the last `Sender` just dropped.
+    fn wake_receivers(state: &mut State<T>) {
+        for waker in state.recv_wakers.drain(..) {
+            waker.wake();
+        }
+    }
+
+    // Wakes every task parked in a bounded `AsyncSender`'s send future because the queue
+    // just made room.
+    fn wake_senders(state: &mut State<T>) {
+        for waker in state.send_wakers.drain(..) {
+            waker.wake();
+        }
+    }
+}
+
+/// Returned by [`Receiver::recv_greatest_blocking`] when the queue is empty and every
+/// [`Sender`] has been dropped, mirroring `std::sync::mpsc::RecvError`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecvError;
+
+impl std::fmt::Display for RecvError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "receiving on an empty and disconnected channel")
+    }
+}
+
+impl std::error::Error for RecvError {}
+
+/// Returned by [`Receiver::recv_greatest_timeout`], mirroring
+/// `std::sync::mpsc::RecvTimeoutError`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecvTimeoutError {
+    /// No item arrived before the deadline elapsed.
+    Timeout,
+    /// The queue is empty and every `Sender` has been dropped.
+    Disconnected,
+}
+
+impl std::fmt::Display for RecvTimeoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecvTimeoutError::Timeout => write!(f, "timed out waiting on channel"),
+            RecvTimeoutError::Disconnected => {
+                write!(f, "receiving on an empty and disconnected channel")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RecvTimeoutError {}
+
+/// Returned by [`Sender::send`] when the channel is bounded, already at capacity, and every
+/// `Receiver` has been dropped, mirroring `std::sync::mpsc::SendError`. Hands the item back
+/// since it can never be delivered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SendError<T>(pub T);
+
+impl<T> std::fmt::Display for SendError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "sending on a disconnected channel")
+    }
+}
+
+impl<T: std::fmt::Debug> std::error::Error for SendError<T> {}
+
+/// Returned by [`Sender::try_send`] when the channel is bounded and already at capacity,
+/// mirroring `std::sync::mpsc::TrySendError`. Hands the item back so the caller can decide
+/// what to do with it instead of losing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrySendError<T> {
+    /// The channel is at capacity, but at least one `Receiver` is still around to drain it.
+    Full(T),
+    /// The channel is at capacity and every `Receiver` has been dropped, so the item can
+    /// never be delivered.
+    Disconnected(T),
 }
 
+impl<T> std::fmt::Display for TrySendError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TrySendError::Full(_) => write!(f, "sending on a full channel"),
+            TrySendError::Disconnected(_) => write!(f, "sending on a full and disconnected channel"),
+        }
+    }
+}
+
+impl<T: std::fmt::Debug> std::error::Error for TrySendError<T> {}
+
 /// The sender struct allows for sending items across the channel.
 /// For use, see method send.
 pub struct Sender<T> {
@@ -47,12 +166,26 @@ pub struct Sender<T> {
 // We only want to clone the Arc, the inner needs to be shared
 impl<T> Clone for Sender<T> {
     fn clone(&self) -> Self {
+        self.inner.state.lock().expect("Poison error").senders += 1;
         Sender {
             inner: Arc::clone(&self.inner),
         }
     }
 }
 
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut state = self.inner.state.lock().expect("Poison error");
+        state.senders -= 1;
+        if state.senders == 0 {
+            // Wake every blocked receiver so they can observe the disconnect.
+            self.inner.not_empty.notify_all();
+            #[cfg(feature = "async")]
+            Inner::wake_receivers(&mut state);
+        }
+    }
+}
+
 impl<T: std::cmp::Ord> Sender<T> {
     /// The send method allows for sending items across the channel.
     ///
@@ -61,12 +194,76 @@ impl<T: std::cmp::Ord> Sender<T> {
     /// use pmpmc::pmpmc;
     /// let (tx, rx) = pmpmc();
     ///
-    /// assert_eq!(tx.send(3), ());
-    /// assert_eq!(tx.send(1), ());
-    /// assert_eq!(tx.send(2), ());
+    /// assert_eq!(tx.send(3), Ok(()));
+    /// assert_eq!(tx.send(1), Ok(()));
+    /// assert_eq!(tx.send(2), Ok(()));
+    /// ```
+    ///
+    /// On a channel built with [`pmpmc_bounded`], `send` blocks until the queue has room
+    /// instead of growing it without bound, and gives up with `Err(SendError(item))` if every
+    /// `Receiver` drops while it's waiting, since the queue can then never drain.
+    pub fn send(&self, item: T) -> Result<(), SendError<T>> {
+        let mut state = self.inner.state.lock().expect("Poison error");
+        if let Some(capacity) = self.inner.capacity {
+            while state.queue.len() >= capacity {
+                if state.receivers == 0 {
+                    return Err(SendError(item));
+                }
+                state = self.inner.not_full.wait(state).expect("Poison error");
+            }
+        }
+        state.queue.push(item);
+        #[cfg(feature = "async")]
+        Inner::wake_receivers(&mut state);
+        drop(state);
+        self.inner.not_empty.notify_one();
+        Ok(())
+    }
+
+    /// Like [`send`](Sender::send), but on a channel built with [`pmpmc_bounded`] this returns
+    /// the item back instead of blocking when the queue is at capacity: `Err(TrySendError::Full)`
+    /// if a `Receiver` is still around to drain it, or `Err(TrySendError::Disconnected)` if every
+    /// `Receiver` has already dropped. On an unbounded channel this never fails.
+    ///
+    /// # Example
+    /// ```
+    /// use pmpmc::{pmpmc_bounded, TrySendError};
+    /// let (tx, _rx) = pmpmc_bounded(1);
+    ///
+    /// assert_eq!(tx.try_send(1), Ok(()));
+    /// assert_eq!(tx.try_send(2), Err(TrySendError::Full(2)));
     /// ```
-    pub fn send(&self, item: T) {
-        self.inner.queue.lock().expect("Poison error").push(item);
+    pub fn try_send(&self, item: T) -> Result<(), TrySendError<T>> {
+        let mut state = self.inner.state.lock().expect("Poison error");
+        if let Some(capacity) = self.inner.capacity {
+            if state.queue.len() >= capacity {
+                if state.receivers == 0 {
+                    return Err(TrySendError::Disconnected(item));
+                }
+                return Err(TrySendError::Full(item));
+            }
+        }
+        state.queue.push(item);
+        #[cfg(feature = "async")]
+        Inner::wake_receivers(&mut state);
+        drop(state);
+        self.inner.not_empty.notify_one();
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: std::cmp::Ord> Sender<T> {
+    /// Converts this into an [`async_support::AsyncSender`] backed by the same channel, for
+    /// use from an async executor instead of a blocking thread.
+    pub fn into_async(self) -> async_support::AsyncSender<T> {
+        // `Sender` has a `Drop` impl that decrements the sender count, so we can't partially
+        // move `inner` out of it; `ManuallyDrop` lets us lift the `Arc` without running that
+        // decrement, handing the same logical "one sender" slot over to the `AsyncSender`.
+        let this = std::mem::ManuallyDrop::new(self);
+        async_support::AsyncSender {
+            inner: Arc::clone(&this.inner),
+        }
     }
 }
 
@@ -78,12 +275,27 @@ pub struct Receiver<T> {
 
 impl<T> Clone for Receiver<T> {
     fn clone(&self) -> Self {
+        self.inner.state.lock().expect("Poison error").receivers += 1;
         Receiver {
             inner: Arc::clone(&self.inner),
         }
     }
 }
 
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        let mut state = self.inner.state.lock().expect("Poison error");
+        state.receivers -= 1;
+        if state.receivers == 0 {
+            // Wake every sender blocked on a full bounded queue so they can observe the
+            // disconnect instead of waiting for room that will never open up.
+            self.inner.not_full.notify_all();
+            #[cfg(feature = "async")]
+            Inner::wake_senders(&mut state);
+        }
+    }
+}
+
 impl<T: std::cmp::Ord> Receiver<T> {
     /// Sorts the elements in the channel and returns Some(greatest) or a None if the channel is empty
     /// # Example
@@ -91,16 +303,191 @@ impl<T: std::cmp::Ord> Receiver<T> {
     /// use pmpmc::pmpmc;
     /// let (tx, rx) = pmpmc();
     ///
-    /// assert_eq!(tx.send(3), ());
-    /// assert_eq!(tx.send(1), ());
-    /// assert_eq!(tx.send(2), ());
+    /// assert_eq!(tx.send(3), Ok(()));
+    /// assert_eq!(tx.send(1), Ok(()));
+    /// assert_eq!(tx.send(2), Ok(()));
     /// assert_eq!(rx.recv_greatest(), Some(3));
     /// assert_eq!(rx.recv_greatest(), Some(2));
     /// assert_eq!(rx.recv_greatest(), Some(1));
     /// ```
     pub fn recv_greatest(&self) -> Option<T> {
-        let mut queue = self.inner.queue.lock().expect("Poison error");
-        queue.pop()
+        let mut state = self.inner.state.lock().expect("Poison error");
+        let item = state.queue.pop();
+        if item.is_some() {
+            #[cfg(feature = "async")]
+            Inner::wake_senders(&mut state);
+        }
+        drop(state);
+        if item.is_some() {
+            // A bounded sender may be parked waiting for room; let it recheck.
+            self.inner.not_full.notify_one();
+        }
+        item
+    }
+
+    /// Like [`recv_greatest`](Receiver::recv_greatest), but parks the calling thread instead of
+    /// returning `None` when the channel is momentarily empty. Wakes as soon as an item arrives,
+    /// and only gives up once every `Sender` has been dropped with nothing left to receive.
+    ///
+    /// # Example
+    /// ```
+    /// use pmpmc::pmpmc;
+    /// let (tx, rx) = pmpmc();
+    ///
+    /// let handle = std::thread::spawn(move || rx.recv_greatest_blocking());
+    /// tx.send(3).unwrap();
+    /// assert_eq!(handle.join().unwrap(), Ok(3));
+    /// ```
+    pub fn recv_greatest_blocking(&self) -> Result<T, RecvError> {
+        let mut state = self.inner.state.lock().expect("Poison error");
+        loop {
+            if let Some(item) = state.queue.pop() {
+                #[cfg(feature = "async")]
+                Inner::wake_senders(&mut state);
+                drop(state);
+                self.inner.not_full.notify_one();
+                return Ok(item);
+            }
+            if state.senders == 0 {
+                return Err(RecvError);
+            }
+            state = self.inner.not_empty.wait(state).expect("Poison error");
+        }
+    }
+
+    /// Like [`recv_greatest_blocking`](Receiver::recv_greatest_blocking), but gives up once
+    /// `timeout` has elapsed instead of waiting forever. Spurious wakeups don't reset the
+    /// deadline: the remaining time is tracked across wakeups, so the call returns
+    /// `Err(RecvTimeoutError::Timeout)` once `timeout` has elapsed since it was made, rather
+    /// than waiting a fresh `timeout` after each wakeup. Like `Condvar::wait_timeout`, it may
+    /// return somewhat after the deadline.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use pmpmc::{pmpmc, RecvTimeoutError};
+    /// let (_tx, rx) = pmpmc::<i32>();
+    ///
+    /// assert_eq!(
+    ///     rx.recv_greatest_timeout(Duration::from_millis(10)),
+    ///     Err(RecvTimeoutError::Timeout)
+    /// );
+    /// ```
+    pub fn recv_greatest_timeout(&self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+        let deadline = Instant::now() + timeout;
+        let mut state = self.inner.state.lock().expect("Poison error");
+        loop {
+            if let Some(item) = state.queue.pop() {
+                #[cfg(feature = "async")]
+                Inner::wake_senders(&mut state);
+                drop(state);
+                self.inner.not_full.notify_one();
+                return Ok(item);
+            }
+            if state.senders == 0 {
+                return Err(RecvTimeoutError::Disconnected);
+            }
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(RecvTimeoutError::Timeout);
+            }
+            let (guard, _) = self
+                .inner
+                .not_empty
+                .wait_timeout(state, remaining)
+                .expect("Poison error");
+            state = guard;
+        }
+    }
+
+    /// Returns an iterator that drains whatever is currently queued, highest priority first,
+    /// and stops (yielding `None`) as soon as the heap is empty instead of blocking for more.
+    /// Backed by [`recv_greatest`](Receiver::recv_greatest).
+    ///
+    /// # Example
+    /// ```
+    /// use pmpmc::pmpmc;
+    /// let (tx, rx) = pmpmc();
+    /// tx.send(1).unwrap();
+    /// tx.send(3).unwrap();
+    /// tx.send(2).unwrap();
+    ///
+    /// let drained: Vec<i32> = rx.try_iter().collect();
+    /// assert_eq!(drained, vec![3, 2, 1]);
+    /// ```
+    pub fn try_iter(&self) -> TryIter<'_, T> {
+        TryIter { receiver: self }
+    }
+
+    /// Returns an iterator that yields the next-greatest item in priority order, blocking for
+    /// each one. The iterator terminates (yielding `None`) once every `Sender` has been dropped
+    /// and the queue is empty. Backed by [`recv_greatest_blocking`](Receiver::recv_greatest_blocking).
+    ///
+    /// # Example
+    /// ```
+    /// use pmpmc::pmpmc;
+    /// let (tx, rx) = pmpmc();
+    /// let handle = std::thread::spawn(move || rx.iter().collect::<Vec<i32>>());
+    ///
+    /// tx.send(1).unwrap();
+    /// tx.send(3).unwrap();
+    /// tx.send(2).unwrap();
+    /// drop(tx);
+    ///
+    /// assert_eq!(handle.join().unwrap(), vec![3, 2, 1]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { receiver: self }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: std::cmp::Ord> Receiver<T> {
+    /// Converts this into an [`async_support::AsyncReceiver`] backed by the same channel, for
+    /// use from an async executor instead of a blocking thread.
+    pub fn into_async(self) -> async_support::AsyncReceiver<T> {
+        // `Receiver` has a `Drop` impl that decrements the receiver count, so we can't
+        // partially move `inner` out of it; `ManuallyDrop` lifts the `Arc` without running
+        // that decrement, handing the same logical "one receiver" slot to the `AsyncReceiver`.
+        let this = std::mem::ManuallyDrop::new(self);
+        async_support::AsyncReceiver {
+            inner: Arc::clone(&this.inner),
+        }
+    }
+}
+
+/// Iterator returned by [`Receiver::try_iter`].
+pub struct TryIter<'a, T> {
+    receiver: &'a Receiver<T>,
+}
+
+impl<'a, T: std::cmp::Ord> Iterator for TryIter<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.receiver.recv_greatest()
+    }
+}
+
+/// Iterator returned by [`Receiver::iter`] and by [`Receiver`]'s `IntoIterator` impl.
+pub struct Iter<'a, T> {
+    receiver: &'a Receiver<T>,
+}
+
+impl<'a, T: std::cmp::Ord> Iterator for Iter<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.receiver.recv_greatest_blocking().ok()
+    }
+}
+
+impl<'a, T: std::cmp::Ord> IntoIterator for &'a Receiver<T> {
+    type Item = T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
     }
 }
 
@@ -110,13 +497,48 @@ impl<T: std::cmp::Ord> Receiver<T> {
 /// ```
 /// use pmpmc::pmpmc;
 /// let (tx, rx) = pmpmc();
-/// tx.send(3);
+/// tx.send(3).unwrap();
 /// assert_eq!(rx.recv_greatest(), Some(3));
 /// ```
 /// The compiler will infer the type once an item is sent.
 pub fn pmpmc<T: std::cmp::Ord>() -> (Sender<T>, Receiver<T>) {
+    new_channel(None)
+}
+
+/// Like [`pmpmc`], but caps the channel at `capacity` elements. Once the heap is full,
+/// [`Sender::send`] blocks until a [`Receiver`] makes room, and [`Sender::try_send`] returns
+/// `Err(TrySendError::Full(item))` instead of blocking. Useful for giving producers
+/// backpressure instead of letting an unbounded heap grow without limit.
+///
+/// # Example
+/// ```
+/// use pmpmc::{pmpmc_bounded, TrySendError};
+/// let (tx, rx) = pmpmc_bounded(1);
+///
+/// tx.send(3).unwrap();
+/// assert_eq!(tx.try_send(1), Err(TrySendError::Full(1)));
+/// assert_eq!(rx.recv_greatest(), Some(3));
+/// tx.send(1).unwrap();
+/// assert_eq!(rx.recv_greatest(), Some(1));
+/// ```
+pub fn pmpmc_bounded<T: std::cmp::Ord>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    new_channel(Some(capacity))
+}
+
+fn new_channel<T: std::cmp::Ord>(capacity: Option<usize>) -> (Sender<T>, Receiver<T>) {
     let inner = Inner {
-        queue: Mutex::new(BinaryHeap::new()),
+        state: Mutex::new(State {
+            queue: BinaryHeap::new(),
+            senders: 1,
+            receivers: 1,
+            #[cfg(feature = "async")]
+            recv_wakers: Vec::new(),
+            #[cfg(feature = "async")]
+            send_wakers: Vec::new(),
+        }),
+        not_empty: Condvar::new(),
+        not_full: Condvar::new(),
+        capacity,
     };
     let shared_inner = Arc::new(inner);
     (
@@ -141,9 +563,9 @@ mod tests {
         let rx_new = rx.clone();
 
         let _ = std::thread::spawn(move || {
-            tx_new.send(2);
-            tx_new.send(1);
-            tx_new.send(3);
+            tx_new.send(2).unwrap();
+            tx_new.send(1).unwrap();
+            tx_new.send(3).unwrap();
         })
         .join();
 
@@ -157,14 +579,15 @@ mod tests {
     #[test]
     fn basic_functionality() {
         let (tx, rx) = pmpmc();
-        assert_eq!(tx.send(3), ());
-        assert_eq!(tx.send(1), ());
-        assert_eq!(tx.send(2), ());
+        assert_eq!(tx.send(3), Ok(()));
+        assert_eq!(tx.send(1), Ok(()));
+        assert_eq!(tx.send(2), Ok(()));
         assert_eq!(rx.recv_greatest(), Some(3));
         assert_eq!(rx.recv_greatest(), Some(2));
         assert_eq!(rx.recv_greatest(), Some(1));
     }
 
+    #[derive(Debug)]
     struct TestStruct {
         matters: u32,
         _does_not_matter: u32,
@@ -203,9 +626,9 @@ mod tests {
         };
 
         let (tx, rx) = pmpmc();
-        assert_eq!(tx.send(second), ());
-        assert_eq!(tx.send(third), ());
-        assert_eq!(tx.send(first), ());
+        tx.send(second).unwrap();
+        tx.send(third).unwrap();
+        tx.send(first).unwrap();
 
         assert_eq!(rx.recv_greatest().unwrap().matters, 3);
         assert_eq!(rx.recv_greatest().unwrap().matters, 2);
@@ -219,13 +642,13 @@ mod tests {
         let tx2 = tx.clone();
         let tx3 = tx.clone();
         let handle1 = std::thread::spawn(move || {
-            tx1.send(5);
+            tx1.send(5).unwrap();
         });
         let handle2 = std::thread::spawn(move || {
-            tx2.send(4);
+            tx2.send(4).unwrap();
         });
         let handle3 = std::thread::spawn(move || {
-            tx3.send(6);
+            tx3.send(6).unwrap();
         });
         let _ = handle1.join();
         let _ = handle2.join();
@@ -242,9 +665,9 @@ mod tests {
         let rx2 = rx.clone();
         let rx3 = rx.clone();
 
-        tx.send(5);
-        tx.send(7);
-        tx.send(6);
+        tx.send(5).unwrap();
+        tx.send(7).unwrap();
+        tx.send(6).unwrap();
 
         let handle1 = std::thread::spawn(move || {
             assert_eq!(rx1.recv_greatest(), Some(7));
@@ -259,4 +682,145 @@ mod tests {
         });
         let _ = handle3.join();
     }
+
+    #[test]
+    fn recv_greatest_blocking_waits_for_a_send() {
+        let (tx, rx) = pmpmc();
+        let handle = std::thread::spawn(move || rx.recv_greatest_blocking());
+
+        // Give the receiver a head start so it actually has to block and wake up.
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        tx.send(3).unwrap();
+
+        assert_eq!(handle.join().unwrap(), Ok(3));
+    }
+
+    #[test]
+    fn recv_greatest_blocking_errors_once_all_senders_drop() {
+        let (tx, rx) = pmpmc::<i32>();
+        let handle = std::thread::spawn(move || rx.recv_greatest_blocking());
+
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        drop(tx);
+
+        assert_eq!(handle.join().unwrap(), Err(RecvError));
+    }
+
+    #[test]
+    fn recv_greatest_timeout_returns_timeout_when_nothing_arrives() {
+        let (_tx, rx) = pmpmc::<i32>();
+        assert_eq!(
+            rx.recv_greatest_timeout(Duration::from_millis(20)),
+            Err(RecvTimeoutError::Timeout)
+        );
+    }
+
+    #[test]
+    fn recv_greatest_timeout_returns_the_item_if_it_arrives_in_time() {
+        let (tx, rx) = pmpmc();
+        let handle = std::thread::spawn(move || rx.recv_greatest_timeout(Duration::from_secs(5)));
+
+        std::thread::sleep(Duration::from_millis(50));
+        tx.send(3).unwrap();
+
+        assert_eq!(handle.join().unwrap(), Ok(3));
+    }
+
+    #[test]
+    fn recv_greatest_timeout_returns_disconnected_once_all_senders_drop() {
+        let (tx, rx) = pmpmc::<i32>();
+        let handle = std::thread::spawn(move || rx.recv_greatest_timeout(Duration::from_secs(5)));
+
+        std::thread::sleep(Duration::from_millis(50));
+        drop(tx);
+
+        assert_eq!(handle.join().unwrap(), Err(RecvTimeoutError::Disconnected));
+    }
+
+    #[test]
+    fn try_iter_drains_in_priority_order_then_stops() {
+        let (tx, rx) = pmpmc();
+        tx.send(1).unwrap();
+        tx.send(3).unwrap();
+        tx.send(2).unwrap();
+
+        let drained: Vec<i32> = rx.try_iter().collect();
+        assert_eq!(drained, vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn iter_blocks_until_every_sender_drops() {
+        let (tx, rx) = pmpmc();
+        tx.send(1).unwrap();
+        tx.send(3).unwrap();
+        tx.send(2).unwrap();
+
+        let handle = std::thread::spawn(move || rx.iter().collect::<Vec<i32>>());
+        std::thread::sleep(Duration::from_millis(50));
+        drop(tx);
+
+        assert_eq!(handle.join().unwrap(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn into_iter_on_receiver_reference_matches_iter() {
+        let (tx, rx) = pmpmc();
+        tx.send(1).unwrap();
+        tx.send(2).unwrap();
+        drop(tx);
+
+        let collected: Vec<i32> = (&rx).into_iter().collect();
+        assert_eq!(collected, vec![2, 1]);
+    }
+
+    #[test]
+    fn try_send_returns_full_once_at_capacity() {
+        let (tx, _rx) = pmpmc_bounded(2);
+        assert_eq!(tx.try_send(1), Ok(()));
+        assert_eq!(tx.try_send(2), Ok(()));
+        assert_eq!(tx.try_send(3), Err(TrySendError::Full(3)));
+    }
+
+    #[test]
+    fn recv_greatest_makes_room_for_a_blocked_send() {
+        let (tx, rx) = pmpmc_bounded(1);
+        tx.send(1).unwrap();
+
+        let handle = std::thread::spawn(move || tx.send(2));
+        std::thread::sleep(Duration::from_millis(50));
+
+        assert_eq!(rx.recv_greatest(), Some(1));
+        handle.join().unwrap().unwrap();
+        assert_eq!(rx.recv_greatest(), Some(2));
+    }
+
+    #[test]
+    fn unbounded_try_send_never_fails() {
+        let (tx, rx) = pmpmc();
+        for i in 0..100 {
+            assert_eq!(tx.try_send(i), Ok(()));
+        }
+        assert_eq!(rx.recv_greatest(), Some(99));
+    }
+
+    #[test]
+    fn send_errors_instead_of_hanging_once_the_last_receiver_drops_while_blocked() {
+        let (tx, rx) = pmpmc_bounded(1);
+        tx.send(1).unwrap();
+
+        let handle = std::thread::spawn(move || tx.send(2));
+        std::thread::sleep(Duration::from_millis(50));
+        drop(rx);
+
+        assert_eq!(handle.join().unwrap(), Err(SendError(2)));
+    }
+
+    #[test]
+    fn try_send_returns_disconnected_once_every_receiver_drops() {
+        let (tx, rx) = pmpmc_bounded(1);
+        tx.send(1).unwrap();
+        drop(rx);
+
+        assert_eq!(tx.try_send(2), Err(TrySendError::Disconnected(2)));
+    }
 }